@@ -1,11 +1,13 @@
+mod surfaces;
+
 use color_eyre::Result;
-use midir::{MidiOutput, MidiOutputConnection};
 use std::convert::TryFrom;
 use std::io::Read;
 use std::os::unix::net::UnixStream;
 use std::path::Path;
-use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use surfaces::{detect_surface, ControlSurface};
 use tracing::{debug, error, info};
 
 const LED_PACKET_FRAMING: u8 = 0xE0;
@@ -13,6 +15,9 @@ const LED_PACKET_ESCAPE: u8 = 0xD0;
 const LED_BOARDS_TOTAL: usize = 3;
 const CHUNI_LED_BOARD_DATA_LENS: [usize; LED_BOARDS_TOTAL] = [53 * 3, 63 * 3, 31 * 3];
 
+/// Speed for the status-banner scroll text sent via `ControlSurface::scroll_text`.
+const SCROLL_SPEED: u8 = 4;
+
 #[derive(Debug)]
 enum DecodeError {
     Invalid,
@@ -86,74 +91,179 @@ fn slider_to_drum_pads(leds: [Rgb; 31]) -> [Rgb; 8] {
     drum_pads
 }
 
-fn rgb_to_launchkey_velocity(rgb: Rgb) -> u8 {
-    // Launchkey Mini MK3 color palette mapping
-    // Based on the official color palette documentation
-
-    // Calculate color distances to find the closest match
-    let palette = [
-        (0, (0, 0, 0)),        // 0: Black/Off
-        (1, (128, 128, 128)),  // 1: Dark Gray
-        (2, (192, 192, 192)),  // 2: Light Gray
-        (3, (255, 255, 255)),  // 3: White
-        (4, (255, 192, 192)),  // 4: Light Pink
-        (5, (255, 0, 0)),      // 5: Red
-        (6, (192, 0, 0)),      // 6: Dark Red
-        (7, (128, 0, 0)),      // 7: Very Dark Red
-        (8, (255, 192, 128)),  // 8: Light Orange
-        (9, (255, 128, 0)),    // 9: Orange
-        (10, (192, 96, 0)),    // 10: Dark Orange
-        (11, (128, 64, 0)),    // 11: Brown
-        (12, (255, 255, 128)), // 12: Light Yellow
-        (13, (255, 255, 0)),   // 13: Yellow
-        (14, (192, 192, 0)),   // 14: Dark Yellow
-        (15, (128, 128, 0)),   // 15: Olive
-        (16, (192, 255, 128)), // 16: Light Green
-        (17, (128, 255, 0)),   // 17: Bright Green
-        (18, (96, 192, 0)),    // 18: Green
-        (19, (64, 128, 0)),    // 19: Dark Green
-        (20, (192, 255, 192)), // 20: Very Light Green
-        (21, (0, 255, 0)),     // 21: Pure Green
-        (22, (0, 192, 0)),     // 22: Medium Green
-        (23, (0, 128, 0)),     // 23: Forest Green
-        (24, (128, 255, 192)), // 24: Light Mint
-        (25, (0, 255, 128)),   // 25: Mint Green
-        (26, (0, 192, 96)),    // 26: Teal Green
-        (27, (0, 128, 64)),    // 27: Dark Teal
-        (28, (128, 255, 255)), // 28: Light Cyan
-        (29, (0, 255, 255)),   // 29: Cyan
-        (30, (0, 192, 192)),   // 30: Dark Cyan
-        (31, (0, 128, 128)),   // 31: Teal
-        (32, (128, 192, 255)), // 32: Light Blue
-        (33, (0, 128, 255)),   // 33: Sky Blue
-        (34, (0, 96, 192)),    // 34: Blue
-        (35, (0, 64, 128)),    // 35: Dark Blue
-        (36, (128, 128, 255)), // 36: Light Purple
-        (37, (0, 0, 255)),     // 37: Pure Blue
-        (38, (0, 0, 192)),     // 38: Medium Blue
-        (39, (0, 0, 128)),     // 39: Navy Blue
-        (40, (192, 128, 255)), // 40: Light Violet
-        (41, (128, 0, 255)),   // 41: Purple
-        (42, (96, 0, 192)),    // 42: Dark Purple
-        (43, (64, 0, 128)),    // 43: Very Dark Purple
-        (44, (255, 128, 255)), // 44: Light Magenta
-        (45, (255, 0, 255)),   // 45: Magenta
-        (46, (192, 0, 192)),   // 46: Dark Magenta
-        (47, (128, 0, 128)),   // 47: Purple
-        (48, (255, 128, 192)), // 48: Light Pink
-        (49, (255, 0, 128)),   // 49: Hot Pink
-        (50, (192, 0, 96)),    // 50: Dark Pink
-        (51, (128, 0, 64)),    // 51: Maroon
-    ];
+// Launchkey Mini MK3 color palette mapping
+// Based on the official color palette documentation
+const LAUNCHKEY_PALETTE: [(u8, (u8, u8, u8)); 52] = [
+    (0, (0, 0, 0)),        // 0: Black/Off
+    (1, (128, 128, 128)),  // 1: Dark Gray
+    (2, (192, 192, 192)),  // 2: Light Gray
+    (3, (255, 255, 255)),  // 3: White
+    (4, (255, 192, 192)),  // 4: Light Pink
+    (5, (255, 0, 0)),      // 5: Red
+    (6, (192, 0, 0)),      // 6: Dark Red
+    (7, (128, 0, 0)),      // 7: Very Dark Red
+    (8, (255, 192, 128)),  // 8: Light Orange
+    (9, (255, 128, 0)),    // 9: Orange
+    (10, (192, 96, 0)),    // 10: Dark Orange
+    (11, (128, 64, 0)),    // 11: Brown
+    (12, (255, 255, 128)), // 12: Light Yellow
+    (13, (255, 255, 0)),   // 13: Yellow
+    (14, (192, 192, 0)),   // 14: Dark Yellow
+    (15, (128, 128, 0)),   // 15: Olive
+    (16, (192, 255, 128)), // 16: Light Green
+    (17, (128, 255, 0)),   // 17: Bright Green
+    (18, (96, 192, 0)),    // 18: Green
+    (19, (64, 128, 0)),    // 19: Dark Green
+    (20, (192, 255, 192)), // 20: Very Light Green
+    (21, (0, 255, 0)),     // 21: Pure Green
+    (22, (0, 192, 0)),     // 22: Medium Green
+    (23, (0, 128, 0)),     // 23: Forest Green
+    (24, (128, 255, 192)), // 24: Light Mint
+    (25, (0, 255, 128)),   // 25: Mint Green
+    (26, (0, 192, 96)),    // 26: Teal Green
+    (27, (0, 128, 64)),    // 27: Dark Teal
+    (28, (128, 255, 255)), // 28: Light Cyan
+    (29, (0, 255, 255)),   // 29: Cyan
+    (30, (0, 192, 192)),   // 30: Dark Cyan
+    (31, (0, 128, 128)),   // 31: Teal
+    (32, (128, 192, 255)), // 32: Light Blue
+    (33, (0, 128, 255)),   // 33: Sky Blue
+    (34, (0, 96, 192)),    // 34: Blue
+    (35, (0, 64, 128)),    // 35: Dark Blue
+    (36, (128, 128, 255)), // 36: Light Purple
+    (37, (0, 0, 255)),     // 37: Pure Blue
+    (38, (0, 0, 192)),     // 38: Medium Blue
+    (39, (0, 0, 128)),     // 39: Navy Blue
+    (40, (192, 128, 255)), // 40: Light Violet
+    (41, (128, 0, 255)),   // 41: Purple
+    (42, (96, 0, 192)),    // 42: Dark Purple
+    (43, (64, 0, 128)),    // 43: Very Dark Purple
+    (44, (255, 128, 255)), // 44: Light Magenta
+    (45, (255, 0, 255)),   // 45: Magenta
+    (46, (192, 0, 192)),   // 46: Dark Magenta
+    (47, (128, 0, 128)),   // 47: Purple
+    (48, (255, 128, 192)), // 48: Light Pink
+    (49, (255, 0, 128)),   // 49: Hot Pink
+    (50, (192, 0, 96)),    // 50: Dark Pink
+    (51, (128, 0, 64)),    // 51: Maroon
+];
+
+/// A color in CIELAB space (L: lightness, a/b: green-red / blue-yellow).
+#[derive(Debug, Clone, Copy)]
+struct Lab {
+    l: f64,
+    a: f64,
+    b: f64,
+}
+
+/// Linearizes a single sRGB channel (already normalized to [0, 1]).
+fn srgb_to_linear(c: f64) -> f64 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// The CIELAB `f(t)` helper used to convert normalized XYZ into L*a*b*.
+fn lab_f(t: f64) -> f64 {
+    if t > 0.008856 {
+        t.powf(1.0 / 3.0)
+    } else {
+        7.787 * t + 16.0 / 116.0
+    }
+}
+
+/// Converts an sRGB color to CIELAB via linear sRGB -> XYZ (D65) -> Lab.
+fn rgb_to_lab(rgb: Rgb) -> Lab {
+    let r = srgb_to_linear(rgb.r as f64 / 255.0);
+    let g = srgb_to_linear(rgb.g as f64 / 255.0);
+    let b = srgb_to_linear(rgb.b as f64 / 255.0);
+
+    let x = 0.4124 * r + 0.3576 * g + 0.1805 * b;
+    let y = 0.2126 * r + 0.7152 * g + 0.0722 * b;
+    let z = 0.0193 * r + 0.1192 * g + 0.9505 * b;
+
+    // Normalize against the D65 reference white.
+    let fx = lab_f(x / 0.95047);
+    let fy = lab_f(y / 1.0);
+    let fz = lab_f(z / 1.08883);
+
+    Lab {
+        l: 116.0 * fy - 16.0,
+        a: 500.0 * (fx - fy),
+        b: 200.0 * (fy - fz),
+    }
+}
+
+/// The CIE76 color difference: Euclidean distance in L*a*b* space.
+fn delta_e76(a: Lab, b: Lab) -> f64 {
+    ((a.l - b.l).powi(2) + (a.a - b.a).powi(2) + (a.b - b.b).powi(2)).sqrt()
+}
+
+/// `LAUNCHKEY_PALETTE`'s entries pre-converted to Lab, computed once since
+/// `rgb_to_lab` involves several per-channel power calls.
+fn launchkey_palette_lab() -> &'static [(u8, Lab)] {
+    static PALETTE_LAB: std::sync::OnceLock<Vec<(u8, Lab)>> = std::sync::OnceLock::new();
+    PALETTE_LAB.get_or_init(|| {
+        LAUNCHKEY_PALETTE
+            .iter()
+            .map(|&(velocity, (r, g, b))| (velocity, rgb_to_lab(Rgb { r, g, b })))
+            .collect()
+    })
+}
+
+// Ableton Push 2 user-mode color palette mapping.
+// Based on the Push 2 MIDI & Display Interface Manual's RGB color table
+// (a distinct hardware palette from the Launchkey's — don't conflate them).
+const PUSH2_PALETTE: [(u8, (u8, u8, u8)); 24] = [
+    (0, (0, 0, 0)),       // 0: Off
+    (1, (30, 30, 30)),    // 1: Dim Gray
+    (2, (82, 82, 82)),    // 2: Gray
+    (3, (177, 177, 177)), // 3: Light Gray
+    (4, (255, 255, 255)), // 4: White
+    (5, (255, 29, 29)),   // 5: Red
+    (6, (172, 0, 0)),     // 6: Dark Red
+    (7, (57, 0, 0)),      // 7: Very Dark Red
+    (8, (255, 137, 47)),  // 8: Orange
+    (9, (132, 68, 0)),    // 9: Dark Orange
+    (10, (255, 255, 45)), // 10: Yellow
+    (11, (124, 124, 0)),  // 11: Dark Yellow
+    (12, (52, 255, 42)),  // 12: Green
+    (13, (0, 124, 0)),    // 13: Dark Green
+    (14, (0, 41, 0)),     // 14: Very Dark Green
+    (15, (55, 255, 163)), // 15: Mint
+    (16, (42, 214, 255)), // 16: Cyan
+    (17, (0, 87, 124)),   // 17: Dark Cyan
+    (18, (42, 95, 255)),  // 18: Blue
+    (19, (0, 0, 124)),    // 19: Dark Blue
+    (20, (138, 42, 255)), // 20: Purple
+    (21, (62, 0, 124)),   // 21: Dark Purple
+    (22, (255, 42, 212)), // 22: Magenta
+    (23, (124, 0, 102)),  // 23: Dark Magenta
+];
+
+/// `PUSH2_PALETTE`'s entries pre-converted to Lab, computed once for the same
+/// reason as `launchkey_palette_lab`.
+fn push2_palette_lab() -> &'static [(u8, Lab)] {
+    static PALETTE_LAB: std::sync::OnceLock<Vec<(u8, Lab)>> = std::sync::OnceLock::new();
+    PALETTE_LAB.get_or_init(|| {
+        PUSH2_PALETTE
+            .iter()
+            .map(|&(velocity, (r, g, b))| (velocity, rgb_to_lab(Rgb { r, g, b })))
+            .collect()
+    })
+}
 
+/// Finds the closest palette entry to `target` by CIE76 ΔE in CIELAB space,
+/// rather than raw sRGB Euclidean distance, since sRGB distance doesn't track
+/// perceived color (it under-weights hue differences humans notice most).
+fn nearest_palette_velocity(target: Lab, palette: &[(u8, Lab)]) -> u8 {
     let mut best_velocity = 0;
     let mut best_distance = f64::INFINITY;
 
-    for (velocity, (pr, pg, pb)) in palette {
-        let distance = ((rgb.r as f64 - pr as f64).powi(2)
-            + (rgb.g as f64 - pg as f64).powi(2)
-            + (rgb.b as f64 - pb as f64).powi(2))
-        .sqrt();
+    for &(velocity, lab) in palette {
+        let distance = delta_e76(target, lab);
 
         if distance < best_distance {
             best_distance = distance;
@@ -164,68 +274,137 @@ fn rgb_to_launchkey_velocity(rgb: Rgb) -> u8 {
     best_velocity
 }
 
-fn send_rgb_to_launchkey(conn: &mut MidiOutputConnection, drum_pads: [Rgb; 8]) -> Result<()> {
-    for (pad_idx, rgb) in drum_pads.iter().enumerate() {
-        // Use bottom drum pads (notes 112-119, 0x70-0x77)
-        let pad_note = 0x70 + pad_idx as u8;
-
-        // Map RGB to Launchkey velocity using color mapping
-        let velocity = rgb_to_launchkey_velocity(*rgb);
+/// Maps an RGB color to the nearest of the 52 Launchkey Mini MK3 palette
+/// entries, for devices driven over plain Note On velocity rather than RGB
+/// SysEx.
+pub(crate) fn rgb_to_launchkey_velocity(rgb: Rgb) -> u8 {
+    nearest_palette_velocity(rgb_to_lab(rgb), launchkey_palette_lab())
+}
 
-        debug!(
-            "Sending pad {} (note {}) â†’ velocity {} (RGB: {}, {}, {})",
-            pad_idx, pad_note, velocity, rgb.r, rgb.g, rgb.b
-        );
+/// Maps an RGB color to the nearest of Push 2's own 24 user-mode palette
+/// entries. Push 2's palette is a different hardware table entirely from the
+/// Launchkey's, so it needs its own nearest-match lookup rather than reusing
+/// `rgb_to_launchkey_velocity`.
+pub(crate) fn rgb_to_push2_velocity(rgb: Rgb) -> u8 {
+    nearest_palette_velocity(rgb_to_lab(rgb), push2_palette_lab())
+}
 
-        // Send Note On message on Channel 1 (0x90) with velocity representing color
-        let note_on_msg = [
-            0x90,     // Note On, Channel 1
-            pad_note, // Note number (drum pad 112-119)
-            velocity, // Velocity (maps to specific color)
-        ];
+/// Lights `surface`'s pad grid with a test pattern (solid green).
+fn send_test_colors(surface: &mut dyn ControlSurface) -> Result<()> {
+    let (width, height) = surface.grid_dimensions();
+    let pad_count = width as usize * height as usize;
 
-        conn.send(&note_on_msg)?;
-    }
+    info!("Sending test colors (all green) to {} pads...", pad_count);
+    surface.set_pads(&vec![Rgb { r: 0, g: 255, b: 0 }; pad_count])?;
+    info!("Test colors sent!");
 
     Ok(())
 }
 
-fn enable_daw_mode(conn: &mut MidiOutputConnection) -> Result<()> {
-    info!("Enabling DAW mode...");
-    conn.send(&[0x9F, 0x0C, 0x7F])?;
-    info!("DAW mode enabled");
-    Ok(())
+/// Overlays the 8 slider-derived drum pad colors onto the bottom row of a
+/// `width`x`height` grid, in place.
+fn overlay_drum_pads(grid: &mut [Rgb], drum_pads: [Rgb; 8], width: u8, height: u8) {
+    let width = width as usize;
+    let height = height as usize;
+
+    if height == 0 {
+        return;
+    }
+
+    let bottom_row_start = (height - 1) * width;
+    let cols_to_fill = drum_pads.len().min(width);
+    grid[bottom_row_start..bottom_row_start + cols_to_fill]
+        .copy_from_slice(&drum_pads[..cols_to_fill]);
 }
 
-fn disable_daw_mode(conn: &mut MidiOutputConnection) -> Result<()> {
-    info!("Disabling DAW mode...");
-    conn.send(&[0x9F, 0x0C, 0x00])?;
-    info!("DAW mode disabled");
-    Ok(())
+/// Lays the 8 slider-derived drum pad colors onto the bottom row of a
+/// `width`x`height` grid, leaving everything above it dark.
+fn drum_pads_to_grid(drum_pads: [Rgb; 8], width: u8, height: u8) -> Vec<Rgb> {
+    let mut grid = vec![Rgb { r: 0, g: 0, b: 0 }; width as usize * height as usize];
+    overlay_drum_pads(&mut grid, drum_pads, width, height);
+    grid
 }
 
-fn send_test_colors(conn: &mut MidiOutputConnection) -> Result<()> {
-    info!("Blacking out top pads (96-103)...");
-
-    // Black out top pads (notes 96-103)
-    for note in 96..=103 {
-        let note_off_msg = [
-            0x90, // Note On, Channel 1
-            note, // Note number (top pads 96-103)
-            0,    // Velocity 0 (off/black)
-        ];
-        conn.send(&note_off_msg)?;
+/// Downsamples a billboard LED strip into `cell_count` cells by averaging
+/// each contiguous run of source LEDs that falls in a cell, the same way
+/// `slider_to_drum_pads` averages runs of 4 slider LEDs per drum pad.
+fn downsample_leds(leds: &[Rgb], cell_count: usize) -> Vec<Rgb> {
+    let len = leds.len();
+    let mut cells = Vec::with_capacity(cell_count);
+
+    for cell_idx in 0..cell_count {
+        let start = cell_idx * len / cell_count;
+        let end = ((cell_idx + 1) * len / cell_count).max(start + 1).min(len);
+
+        let mut total_r = 0u32;
+        let mut total_g = 0u32;
+        let mut total_b = 0u32;
+        let mut count = 0u32;
+
+        for led in &leds[start..end] {
+            total_r += led.r as u32;
+            total_g += led.g as u32;
+            total_b += led.b as u32;
+            count += 1;
+        }
+
+        cells.push(if count > 0 {
+            Rgb {
+                r: (total_r / count) as u8,
+                g: (total_g / count) as u8,
+                b: (total_b / count) as u8,
+            }
+        } else {
+            Rgb { r: 0, g: 0, b: 0 }
+        });
     }
 
-    info!("Sending test colors (all green) to drum pads...");
+    cells
+}
 
-    // Create test drum pads - all green
-    let test_pads = [Rgb { r: 0, g: 255, b: 0 }; 8];
+/// Spatially downsamples the left and right billboard strips onto an 8x8
+/// grid: the left strip feeds the 4 left columns, the right strip the 4
+/// right columns, each averaging contiguous LED runs per cell.
+fn billboard_to_grid(left: &[Rgb; 53], right: &[Rgb; 60]) -> [Rgb; 64] {
+    const GRID_WIDTH: usize = 8;
+    const GRID_HEIGHT: usize = 8;
+    const HALF_WIDTH: usize = GRID_WIDTH / 2;
+    const HALF_CELLS: usize = HALF_WIDTH * GRID_HEIGHT;
+
+    let left_cells = downsample_leds(left, HALF_CELLS);
+    let right_cells = downsample_leds(right, HALF_CELLS);
+
+    let mut grid = [Rgb { r: 0, g: 0, b: 0 }; GRID_WIDTH * GRID_HEIGHT];
+
+    for row in 0..GRID_HEIGHT {
+        for col in 0..HALF_WIDTH {
+            let half_idx = row * HALF_WIDTH + col;
+            grid[row * GRID_WIDTH + col] = left_cells[half_idx];
+            grid[row * GRID_WIDTH + HALF_WIDTH + col] = right_cells[half_idx];
+        }
+    }
 
-    send_rgb_to_launchkey(conn, test_pads)?;
+    grid
+}
 
-    info!("Test colors sent!");
-    Ok(())
+/// Builds the full frame to send to `surface`: the 8x8 billboard light show
+/// with the slider-derived drum pads overlaid as the bottom row, or (for
+/// devices without a real grid, like the Launchkey's single pad row) just
+/// the drum pads.
+fn compose_grid(
+    width: u8,
+    height: u8,
+    drum_pads: [Rgb; 8],
+    billboard_left: &[Rgb; 53],
+    billboard_right: &[Rgb; 60],
+) -> Vec<Rgb> {
+    if (width, height) == (8, 8) {
+        let mut grid = billboard_to_grid(billboard_left, billboard_right).to_vec();
+        overlay_drum_pads(&mut grid, drum_pads, width, height);
+        grid
+    } else {
+        drum_pads_to_grid(drum_pads, width, height)
+    }
 }
 
 fn try_parse_packet(buf: &[u8]) -> Result<(LedPacket, usize), DecodeError> {
@@ -316,41 +495,40 @@ fn main() -> Result<()> {
     // Set up signal handler
     let running = setup_signal_handler();
 
-    // Track last sent drum pad state to prevent redundant messages
-    let mut last_drum_pads: Option<[Rgb; 8]> = None;
-
-    // Initialize MIDI output
-    let midi_output = MidiOutput::new("chunimidi")?;
-    let out_ports = midi_output.ports();
+    // Latest state from each board, composed into a frame whenever any of
+    // them changes; the frame is sent only if it differs from the last one sent
+    let mut last_drum_pads = [Rgb { r: 0, g: 0, b: 0 }; 8];
+    let mut last_billboard_left = [Rgb { r: 0, g: 0, b: 0 }; 53];
+    let mut last_billboard_right = [Rgb { r: 0, g: 0, b: 0 }; 60];
+    let mut last_sent_grid: Option<Vec<Rgb>> = None;
 
-    // Find the Launchkey Mk3 at port 16:1
-    let launchkey_port = out_ports
-        .iter()
-        .find(|port| {
-            let port_name = midi_output.port_name(port).unwrap_or_default();
-            debug!("Found MIDI port: {}", port_name);
-            port_name.contains("16:1")
-        })
-        .ok_or_else(|| color_eyre::eyre::eyre!("Launchkey MK3 not found"))?;
-    let mut midi_conn = midi_output
-        .connect(launchkey_port, "chunimidi-launchkey")
-        .map_err(|e| color_eyre::eyre::eyre!("Failed to connect to MIDI device: {}", e))?;
-    info!("Connected to Launchkey MK3");
+    // Initialize MIDI output and find whichever supported control surface is plugged in
+    let midi_output = midir::MidiOutput::new("chunimidi")?;
+    let mut surface = detect_surface(midi_output)?;
+    let grid_dimensions = surface.grid_dimensions();
 
-    // Enable DAW mode (includes programmer mode)
-    enable_daw_mode(&mut midi_conn)?;
+    // Enter programmer/DAW mode (whatever exposes raw pad control on this device)
+    surface.enter_programmer_mode()?;
 
     // Send test colors
-    send_test_colors(&mut midi_conn)?;
+    send_test_colors(surface.as_mut())?;
 
     // Enable real LED data processing when socket is available
     let socket_path = "/tmp/chuni.sock"; // Change to your socket path
 
+    if let Err(e) = surface.scroll_text(&format!("CONNECTING {}", socket_path), SCROLL_SPEED) {
+        debug!("Failed to scroll status banner: {}", e);
+    }
+
     // Try to connect to LED socket, but continue without it if not available
     match UnixStream::connect(Path::new(socket_path)) {
         Ok(mut stream) => {
             info!("Connected to LED socket: {}", socket_path);
 
+            if let Err(e) = surface.scroll_text("CONNECTED", SCROLL_SPEED) {
+                debug!("Failed to scroll status banner: {}", e);
+            }
+
             let mut buf = vec![0u8; 4096];
             let mut window = Vec::<u8>::new();
 
@@ -371,31 +549,41 @@ fn main() -> Result<()> {
                         Ok((packet, used)) => {
                             // debug!("Decoded LED packet: {:?}", packet);
 
-                            // If it's a slider packet, also show the drum pad conversion
-                            if let LedBoard::Slider(slider_leds) = &packet.payload {
-                                let drum_pads = slider_to_drum_pads(*slider_leds);
-                                // debug!("Drum pads (8 zones): {:?}", drum_pads);
-
-                                // Only send RGB data to Launchkey Mk3 if colors have changed
-                                let should_send = match &last_drum_pads {
-                                    None => true,                     // First time, always send
-                                    Some(last) => *last != drum_pads, // Only send if different
-                                };
-
-                                if should_send {
-                                    if let Err(e) = send_rgb_to_launchkey(&mut midi_conn, drum_pads)
-                                    {
-                                        error!("Failed to send MIDI data: {}", e);
-                                    } else {
-                                        debug!(
-                                            "Updated Launchkey colors (changed from previous state)"
-                                        );
-                                        last_drum_pads = Some(drum_pads);
-                                    }
+                            match &packet.payload {
+                                LedBoard::Slider(slider_leds) => {
+                                    last_drum_pads = slider_to_drum_pads(*slider_leds);
+                                }
+                                LedBoard::BillboardLeft(leds, _sides) => {
+                                    last_billboard_left = *leds;
+                                }
+                                LedBoard::BillboardRight(leds, _sides) => {
+                                    last_billboard_right = *leds;
+                                }
+                            }
+
+                            let grid = compose_grid(
+                                grid_dimensions.0,
+                                grid_dimensions.1,
+                                last_drum_pads,
+                                &last_billboard_left,
+                                &last_billboard_right,
+                            );
+
+                            // Only send RGB data to the surface if colors have changed
+                            let should_send = last_sent_grid.as_deref() != Some(grid.as_slice());
+
+                            if should_send {
+                                if let Err(e) = surface.set_pads(&grid) {
+                                    error!("Failed to send MIDI data: {}", e);
                                 } else {
-                                    // Uncomment the line below if you want to see when updates are skipped
-                                    // debug!("Skipping MIDI update (colors unchanged)");
+                                    debug!(
+                                        "Updated control surface colors (changed from previous state)"
+                                    );
+                                    last_sent_grid = Some(grid);
                                 }
+                            } else {
+                                // Uncomment the line below if you want to see when updates are skipped
+                                // debug!("Skipping MIDI update (colors unchanged)");
                             }
 
                             window.drain(0..used);
@@ -415,6 +603,10 @@ fn main() -> Result<()> {
             );
             info!("Press Ctrl+C to exit...");
 
+            if let Err(e) = surface.scroll_text("NO SOCKET", SCROLL_SPEED) {
+                debug!("Failed to scroll status banner: {}", e);
+            }
+
             // Just wait for signal to exit
             while running.load(Ordering::SeqCst) {
                 std::thread::sleep(std::time::Duration::from_millis(100));
@@ -422,10 +614,10 @@ fn main() -> Result<()> {
         }
     }
 
-    // Cleanup: disable DAW mode before exiting
+    // Cleanup: leave programmer mode before exiting
     info!("Shutting down...");
-    if let Err(e) = disable_daw_mode(&mut midi_conn) {
-        error!("Failed to disable DAW mode: {}", e);
+    if let Err(e) = surface.exit_programmer_mode() {
+        error!("Failed to exit programmer mode: {}", e);
     }
 
     Ok(())
@@ -0,0 +1,488 @@
+//! Device-agnostic lighting backends.
+//!
+//! The chuni LED stream itself is device-agnostic (see `try_parse_packet` in
+//! `main`); only the output side is hardware-specific. Each [`ControlSurface`]
+//! impl owns its own port discovery, programmer-mode init/teardown, and pad
+//! color encoding, so the main loop can drive whichever controller is plugged
+//! in without caring about its wire format.
+
+use crate::Rgb;
+use color_eyre::Result;
+use midir::{MidiOutput, MidiOutputConnection};
+use tracing::{debug, info};
+
+/// A MIDI grid controller that can display an RGB pad grid.
+///
+/// Implementations are responsible for finding their own output port,
+/// entering/leaving whatever "programmer"/DAW mode exposes raw pad control,
+/// and translating an `Rgb` grid into the device's native color encoding.
+pub trait ControlSurface {
+    /// Connects to this surface's MIDI output port.
+    ///
+    /// Callers should check the port is actually present (e.g. via a
+    /// `detect_surface`-style scan) before calling this, since it fails if no
+    /// matching port exists.
+    fn connect(midi_output: MidiOutput) -> Result<Self>
+    where
+        Self: Sized;
+
+    /// Switches the device into the mode that exposes raw pad lighting control.
+    fn enter_programmer_mode(&mut self) -> Result<()>;
+
+    /// Restores the device's normal (non-programmer) mode.
+    fn exit_programmer_mode(&mut self) -> Result<()>;
+
+    /// Lights the pad grid. `pads` is row-major and must have
+    /// `grid_dimensions().0 * grid_dimensions().1` entries.
+    fn set_pads(&mut self, pads: &[Rgb]) -> Result<()>;
+
+    /// Returns the pad grid size as `(width, height)`.
+    fn grid_dimensions(&self) -> (u8, u8);
+
+    /// Scrolls `msg` across the pad grid as a status banner, at `speed`
+    /// (device-specific units, typically 0 = slowest).
+    ///
+    /// Devices with no scroll-text SysEx (e.g. Push 2) keep the default
+    /// no-op impl.
+    fn scroll_text(&mut self, msg: &str, speed: u8) -> Result<()> {
+        let _ = (msg, speed);
+        Ok(())
+    }
+}
+
+/// Scales an 0-255 color channel down to a 7-bit MIDI data byte.
+fn channel_to_7bit(v: u8) -> u8 {
+    v >> 1
+}
+
+/// Builds and sends a Novation scroll-text SysEx frame: `<header> <loop>
+/// <speed> <colour> <ascii bytes...> F7`. `header` should end just after the
+/// device's command byte (e.g. `F0 00 20 29 02 0C 07`). Loops once; ASCII
+/// bytes are masked to 7 bits since SysEx data can't carry the high bit.
+fn send_scroll_text(
+    conn: &mut MidiOutputConnection,
+    header: &[u8],
+    msg: &str,
+    speed: u8,
+) -> Result<()> {
+    const NO_LOOP: u8 = 0x00;
+    const COLOUR_GREEN: u8 = 21;
+
+    let mut sysex = header.to_vec();
+    sysex.push(NO_LOOP);
+    sysex.push(speed & 0x7F);
+    sysex.push(COLOUR_GREEN);
+    sysex.extend(msg.bytes().map(|b| b & 0x7F));
+    sysex.push(0xF7);
+
+    debug!("Scrolling text {:?} at speed {}", msg, speed);
+    conn.send(&sysex)?;
+
+    Ok(())
+}
+
+/// Novation Launchkey Mini MK3, driven through its DAW/programmer mode.
+///
+/// Supports two pad color encodings, selected by `CHUNIMIDI_PAD_MODE`:
+/// palette-indexed Note On velocities (works on any firmware) or a single
+/// SysEx frame carrying true per-pad RGB.
+pub struct LaunchkeyMiniMk3 {
+    conn: MidiOutputConnection,
+    pad_mode: PadOutputMode,
+    /// Previous frame's color per pad, used to detect a note hit (a sharp
+    /// jump from near-black to bright) to trigger the flash channel.
+    pad_history: [Rgb; LaunchkeyMiniMk3::PAD_COUNT],
+    /// Frames of flash-channel output remaining per pad before it decays
+    /// back to the static channel.
+    flash_frames_remaining: [u8; LaunchkeyMiniMk3::PAD_COUNT],
+    flash_decay_frames: u8,
+}
+
+impl LaunchkeyMiniMk3 {
+    /// Substring that identifies this device's MIDI output port.
+    pub const PORT_HINT: &'static str = "16:1";
+
+    /// 8 bottom drum pads, notes 112-119 (0x70-0x77).
+    const PAD_BASE_NOTE: u8 = 0x70;
+    const PAD_COUNT: usize = 8;
+
+    /// Note On, channel 1: static color.
+    const CHANNEL_STATIC: u8 = 0x90;
+    /// Note On, channel 2: flashes between the static color and this one
+    /// (many Novation grids, e.g. Launchpad X, treat this as a blink).
+    const CHANNEL_FLASH: u8 = 0x91;
+
+    /// Peak-channel brightness below which a pad counts as "dark".
+    const HIT_DARK_THRESHOLD: u8 = 32;
+    /// Peak-channel brightness at/above which a pad counts as "bright". Set
+    /// low enough that a single saturated primary (e.g. pure red or green,
+    /// peak 255) still crosses it, not just multi-channel colors like yellow.
+    const HIT_BRIGHT_THRESHOLD: u8 = 160;
+
+    /// Reads `CHUNIMIDI_FLASH_FRAMES` to pick how many frames a pad stays on
+    /// the flash channel after a hit, defaulting to 8.
+    fn flash_decay_frames() -> u8 {
+        std::env::var("CHUNIMIDI_FLASH_FRAMES")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(8)
+    }
+}
+
+/// Brightness used for hit detection: the brightest single channel, not the
+/// channel sum, so a saturated single-hue color (e.g. pure red or green)
+/// registers as "bright" the same as a multi-channel color of equal peak.
+fn pad_brightness(rgb: Rgb) -> u8 {
+    rgb.r.max(rgb.g).max(rgb.b)
+}
+
+/// Selects how `LaunchkeyMiniMk3` encodes pad colors.
+///
+/// `Palette` quantizes each color down to the nearest of the 52 velocity-indexed
+/// palette entries (works on any firmware). `Rgb` sends a single SysEx frame with
+/// true 7-bit-per-channel color, which requires DAW/programmer mode RGB support.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PadOutputMode {
+    Palette,
+    Rgb,
+}
+
+/// Reads `CHUNIMIDI_PAD_MODE` ("palette" or "rgb") to pick the output path,
+/// defaulting to `Palette` for firmware that doesn't support RGB SysEx.
+fn pad_output_mode() -> PadOutputMode {
+    match std::env::var("CHUNIMIDI_PAD_MODE") {
+        Ok(mode) if mode.eq_ignore_ascii_case("rgb") => PadOutputMode::Rgb,
+        _ => PadOutputMode::Palette,
+    }
+}
+
+impl ControlSurface for LaunchkeyMiniMk3 {
+    fn connect(midi_output: MidiOutput) -> Result<Self> {
+        let port = midi_output
+            .ports()
+            .into_iter()
+            .find(|port| {
+                let port_name = midi_output.port_name(port).unwrap_or_default();
+                debug!("Found MIDI port: {}", port_name);
+                port_name.contains(Self::PORT_HINT)
+            })
+            .ok_or_else(|| color_eyre::eyre::eyre!("Launchkey MK3 not found"))?;
+
+        let conn = midi_output
+            .connect(&port, "chunimidi-launchkey")
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to connect to MIDI device: {}", e))?;
+
+        info!("Connected to Launchkey MK3");
+
+        Ok(Self {
+            conn,
+            pad_mode: pad_output_mode(),
+            pad_history: [Rgb { r: 0, g: 0, b: 0 }; Self::PAD_COUNT],
+            flash_frames_remaining: [0; Self::PAD_COUNT],
+            flash_decay_frames: Self::flash_decay_frames(),
+        })
+    }
+
+    fn enter_programmer_mode(&mut self) -> Result<()> {
+        info!("Enabling DAW mode...");
+        self.conn.send(&[0x9F, 0x0C, 0x7F])?;
+        info!("DAW mode enabled");
+        Ok(())
+    }
+
+    fn exit_programmer_mode(&mut self) -> Result<()> {
+        info!("Disabling DAW mode...");
+        self.conn.send(&[0x9F, 0x0C, 0x00])?;
+        info!("DAW mode disabled");
+        Ok(())
+    }
+
+    fn set_pads(&mut self, pads: &[Rgb]) -> Result<()> {
+        match self.pad_mode {
+            PadOutputMode::Palette => self.send_palette(pads),
+            PadOutputMode::Rgb => self.send_rgb_sysex(pads),
+        }
+    }
+
+    fn grid_dimensions(&self) -> (u8, u8) {
+        (8, 1)
+    }
+
+    fn scroll_text(&mut self, msg: &str, speed: u8) -> Result<()> {
+        send_scroll_text(
+            &mut self.conn,
+            &[0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x07],
+            msg,
+            speed,
+        )
+    }
+}
+
+impl LaunchkeyMiniMk3 {
+    /// Updates `pad_idx`'s hit-detection history and flash countdown for the
+    /// incoming `rgb` frame, returning whether the pad should currently be
+    /// rendered in its flash/pulse state. Shared by both `send_palette` and
+    /// `send_rgb_sysex` so flash behavior doesn't silently diverge between
+    /// the two output modes.
+    fn update_flash_state(&mut self, pad_idx: usize, rgb: Rgb) -> bool {
+        let was_dark = pad_brightness(self.pad_history[pad_idx]) < Self::HIT_DARK_THRESHOLD;
+        let is_hit = was_dark && pad_brightness(rgb) >= Self::HIT_BRIGHT_THRESHOLD;
+        self.pad_history[pad_idx] = rgb;
+
+        if is_hit {
+            self.flash_frames_remaining[pad_idx] = self.flash_decay_frames;
+        } else if self.flash_frames_remaining[pad_idx] > 0 {
+            self.flash_frames_remaining[pad_idx] -= 1;
+        }
+
+        self.flash_frames_remaining[pad_idx] > 0
+    }
+
+    fn send_palette(&mut self, pads: &[Rgb]) -> Result<()> {
+        for (pad_idx, rgb) in pads.iter().enumerate() {
+            let pad_note = Self::PAD_BASE_NOTE + pad_idx as u8;
+            let velocity = crate::rgb_to_launchkey_velocity(*rgb);
+            let is_flashing = self.update_flash_state(pad_idx, *rgb);
+
+            let channel = if is_flashing {
+                Self::CHANNEL_FLASH
+            } else {
+                Self::CHANNEL_STATIC
+            };
+
+            debug!(
+                "Sending pad {} (note {}) -> velocity {} on channel {:#x} (RGB: {}, {}, {})",
+                pad_idx, pad_note, velocity, channel, rgb.r, rgb.g, rgb.b
+            );
+
+            self.conn.send(&[channel, pad_note, velocity])?;
+        }
+
+        Ok(())
+    }
+
+    /// Sends true RGB color to all pads in a single SysEx frame.
+    ///
+    /// Frame layout: header `F0 00 20 29 02 0C 03`, then per pad a
+    /// `<led_index> 03 <r> <g> <b>` triplet (sub-command `03` selects RGB mode),
+    /// terminated by `F7`.
+    ///
+    /// This path has no MIDI-channel concept to carry `send_palette`'s
+    /// blink, so a pad's flash window is instead rendered as a degraded
+    /// equivalent: the color is forced to full white for as long as the pad
+    /// would otherwise be on the flash channel. It's a one-shot brightness
+    /// pulse rather than a true blink, but it keeps hit feedback visible in
+    /// RGB mode instead of dropping it silently.
+    fn send_rgb_sysex(&mut self, pads: &[Rgb]) -> Result<()> {
+        let mut sysex = vec![0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x03];
+
+        for (pad_idx, rgb) in pads.iter().enumerate() {
+            let is_flashing = self.update_flash_state(pad_idx, *rgb);
+            let rgb = if is_flashing {
+                Rgb {
+                    r: 255,
+                    g: 255,
+                    b: 255,
+                }
+            } else {
+                *rgb
+            };
+
+            let led_index = Self::PAD_BASE_NOTE + pad_idx as u8;
+            sysex.push(led_index);
+            sysex.push(0x03); // RGB color spec
+            sysex.push(channel_to_7bit(rgb.r));
+            sysex.push(channel_to_7bit(rgb.g));
+            sysex.push(channel_to_7bit(rgb.b));
+        }
+
+        sysex.push(0xF7);
+
+        debug!("Sending RGB SysEx for {} pads", pads.len());
+        self.conn.send(&sysex)?;
+
+        Ok(())
+    }
+}
+
+/// Novation Launchpad X, driven through its programmer layout.
+pub struct LaunchpadX {
+    conn: MidiOutputConnection,
+}
+
+impl LaunchpadX {
+    /// Substring that identifies this device's MIDI output port.
+    pub const PORT_HINT: &'static str = "Launchpad X";
+
+    /// Launchpad-style note layout: row 0 is notes 11-18, row 1 is 21-28, etc.
+    fn note_for(col: u8, row: u8) -> u8 {
+        11 + row * 10 + col
+    }
+}
+
+impl ControlSurface for LaunchpadX {
+    fn connect(midi_output: MidiOutput) -> Result<Self> {
+        let port = midi_output
+            .ports()
+            .into_iter()
+            .find(|port| {
+                let port_name = midi_output.port_name(port).unwrap_or_default();
+                debug!("Found MIDI port: {}", port_name);
+                port_name.contains(Self::PORT_HINT)
+            })
+            .ok_or_else(|| color_eyre::eyre::eyre!("Launchpad X not found"))?;
+
+        let conn = midi_output
+            .connect(&port, "chunimidi-launchpad-x")
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to connect to MIDI device: {}", e))?;
+
+        info!("Connected to Launchpad X");
+
+        Ok(Self { conn })
+    }
+
+    fn enter_programmer_mode(&mut self) -> Result<()> {
+        info!("Selecting Launchpad X programmer layout...");
+        self.conn
+            .send(&[0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x0E, 0x01, 0xF7])?;
+        Ok(())
+    }
+
+    fn exit_programmer_mode(&mut self) -> Result<()> {
+        info!("Restoring Launchpad X live layout...");
+        self.conn
+            .send(&[0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x0E, 0x00, 0xF7])?;
+        Ok(())
+    }
+
+    fn set_pads(&mut self, pads: &[Rgb]) -> Result<()> {
+        let (width, height) = self.grid_dimensions();
+        let mut sysex = vec![0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x03];
+
+        for row in 0..height {
+            for col in 0..width {
+                let idx = row as usize * width as usize + col as usize;
+                let Some(rgb) = pads.get(idx) else {
+                    continue;
+                };
+
+                sysex.push(Self::note_for(col, row));
+                sysex.push(0x03); // RGB color spec
+                sysex.push(channel_to_7bit(rgb.r));
+                sysex.push(channel_to_7bit(rgb.g));
+                sysex.push(channel_to_7bit(rgb.b));
+            }
+        }
+
+        sysex.push(0xF7);
+
+        debug!("Sending RGB SysEx for {} pads", pads.len());
+        self.conn.send(&sysex)?;
+
+        Ok(())
+    }
+
+    fn grid_dimensions(&self) -> (u8, u8) {
+        (8, 8)
+    }
+
+    fn scroll_text(&mut self, msg: &str, speed: u8) -> Result<()> {
+        send_scroll_text(
+            &mut self.conn,
+            &[0xF0, 0x00, 0x20, 0x29, 0x02, 0x0C, 0x07],
+            msg,
+            speed,
+        )
+    }
+}
+
+/// Ableton Push 2, driven through its User Mode pad grid.
+pub struct Push2 {
+    conn: MidiOutputConnection,
+}
+
+impl Push2 {
+    /// Substring that identifies this device's MIDI output port.
+    pub const PORT_HINT: &'static str = "Ableton Push 2";
+
+    /// Push 2's 8x8 pad grid starts at note 36.
+    const PAD_BASE_NOTE: u8 = 36;
+}
+
+impl ControlSurface for Push2 {
+    fn connect(midi_output: MidiOutput) -> Result<Self> {
+        let port = midi_output
+            .ports()
+            .into_iter()
+            .find(|port| {
+                let port_name = midi_output.port_name(port).unwrap_or_default();
+                debug!("Found MIDI port: {}", port_name);
+                port_name.contains(Self::PORT_HINT)
+            })
+            .ok_or_else(|| color_eyre::eyre::eyre!("Push 2 not found"))?;
+
+        let conn = midi_output
+            .connect(&port, "chunimidi-push2")
+            .map_err(|e| color_eyre::eyre::eyre!("Failed to connect to MIDI device: {}", e))?;
+
+        info!("Connected to Push 2");
+
+        Ok(Self { conn })
+    }
+
+    fn enter_programmer_mode(&mut self) -> Result<()> {
+        info!("Selecting Push 2 User Mode...");
+        self.conn
+            .send(&[0xF0, 0x47, 0x7F, 0x15, 0x62, 0x00, 0x01, 0x01, 0xF7])?;
+        Ok(())
+    }
+
+    fn exit_programmer_mode(&mut self) -> Result<()> {
+        info!("Restoring Push 2 default mode...");
+        self.conn
+            .send(&[0xF0, 0x47, 0x7F, 0x15, 0x62, 0x00, 0x01, 0x00, 0xF7])?;
+        Ok(())
+    }
+
+    fn set_pads(&mut self, pads: &[Rgb]) -> Result<()> {
+        for (pad_idx, rgb) in pads.iter().enumerate() {
+            let pad_note = Self::PAD_BASE_NOTE + pad_idx as u8;
+            let velocity = crate::rgb_to_push2_velocity(*rgb);
+            self.conn.send(&[0x90, pad_note, velocity])?;
+        }
+
+        Ok(())
+    }
+
+    fn grid_dimensions(&self) -> (u8, u8) {
+        (8, 8)
+    }
+}
+
+/// Scans available MIDI output ports and connects to the first known
+/// control surface found, trying devices in the order they're listed here.
+pub fn detect_surface(midi_output: MidiOutput) -> Result<Box<dyn ControlSurface>> {
+    let port_names: Vec<String> = midi_output
+        .ports()
+        .iter()
+        .map(|port| midi_output.port_name(port).unwrap_or_default())
+        .collect();
+
+    let is_present = |hint: &str| port_names.iter().any(|name| name.contains(hint));
+
+    if is_present(LaunchkeyMiniMk3::PORT_HINT) {
+        return Ok(Box::new(LaunchkeyMiniMk3::connect(midi_output)?));
+    }
+
+    if is_present(LaunchpadX::PORT_HINT) {
+        return Ok(Box::new(LaunchpadX::connect(midi_output)?));
+    }
+
+    if is_present(Push2::PORT_HINT) {
+        return Ok(Box::new(Push2::connect(midi_output)?));
+    }
+
+    Err(color_eyre::eyre::eyre!(
+        "No supported control surface found (looked for Launchkey MK3, Launchpad X, Push 2)"
+    ))
+}